@@ -1,127 +1,395 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::parser::Statement;
 use crate::parser::Expr;
 use crate::parser::Prefix;
 use crate::parser::Operator;
+use crate::typecheck;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Object {
     Integer(i32),
+    Float(f64),
     Boolean(bool),
+    String(String),
     Null,
     Return(Box<Object>),
+    Function {
+        params: Vec<String>,
+        body: Vec<Statement>,
+        env: Rc<RefCell<Environment>>,
+    },
+    Builtin(fn(Vec<Object>) -> Result<Object, EvalError>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EvalError {
+    TypeError { operator: String, got: String },
+    UndefinedVariable(String),
+    DivisionByZero,
+    ArityMismatch { expected: usize, got: usize },
+    TypeCheckFailed(Vec<typecheck::TypeError>),
+    Unsupported(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Environment {
+    store: HashMap<String, Object>,
+    parent: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Rc<RefCell<Environment>> {
+        Rc::new(RefCell::new(Environment { store: HashMap::new(), parent: None }))
+    }
+
+    fn child(parent: Rc<RefCell<Environment>>) -> Rc<RefCell<Environment>> {
+        Rc::new(RefCell::new(Environment { store: HashMap::new(), parent: Some(parent) }))
+    }
+
+    fn set(&mut self, name: String, value: Object) {
+        self.store.insert(name, value);
+    }
+
+    fn get(&self, name: &str) -> Option<Object> {
+        self.store
+            .get(name)
+            .cloned()
+            .or_else(|| self.parent.as_ref().and_then(|parent| parent.borrow().get(name)))
+    }
+}
+
+fn eval_block(statements: Vec<Statement>, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
+    let child = Environment::child(Rc::clone(env));
+    eval_statements(statements, &child)
+}
+
+fn as_f64(object: &Object) -> Option<f64> {
+    match object {
+        Object::Integer(val) => Some(*val as f64),
+        Object::Float(val) => Some(*val),
+        _ => None,
+    }
+}
+
+fn lookup_builtin(name: &str) -> Option<Object> {
+    match name {
+        "len" => Some(Object::Builtin(builtin_len)),
+        "min" => Some(Object::Builtin(builtin_min)),
+        "max" => Some(Object::Builtin(builtin_max)),
+        "abs" => Some(Object::Builtin(builtin_abs)),
+        "type" => Some(Object::Builtin(builtin_type)),
+        _ => None,
+    }
+}
+
+fn builtin_len(args: Vec<Object>) -> Result<Object, EvalError> {
+    match args.as_slice() {
+        [Object::String(val)] => Ok(Object::Integer(val.len() as i32)),
+        [other] => Err(EvalError::TypeError {
+            operator: "len".to_string(),
+            got: format!("{:?}", other),
+        }),
+        _ => Err(EvalError::ArityMismatch { expected: 1, got: args.len() }),
+    }
+}
+
+fn builtin_min(args: Vec<Object>) -> Result<Object, EvalError> {
+    if args.is_empty() {
+        return Err(EvalError::ArityMismatch { expected: 1, got: 0 });
+    }
+
+    let mut result = None;
+    for arg in args {
+        match arg {
+            Object::Integer(val) => result = Some(result.map_or(val, |min: i32| min.min(val))),
+            other => return Err(EvalError::TypeError {
+                operator: "min".to_string(),
+                got: format!("{:?}", other),
+            }),
+        }
+    }
+
+    Ok(Object::Integer(result.unwrap()))
+}
+
+fn builtin_max(args: Vec<Object>) -> Result<Object, EvalError> {
+    if args.is_empty() {
+        return Err(EvalError::ArityMismatch { expected: 1, got: 0 });
+    }
+
+    let mut result = None;
+    for arg in args {
+        match arg {
+            Object::Integer(val) => result = Some(result.map_or(val, |max: i32| max.max(val))),
+            other => return Err(EvalError::TypeError {
+                operator: "max".to_string(),
+                got: format!("{:?}", other),
+            }),
+        }
+    }
+
+    Ok(Object::Integer(result.unwrap()))
+}
+
+fn builtin_abs(args: Vec<Object>) -> Result<Object, EvalError> {
+    match args.as_slice() {
+        [Object::Integer(val)] => Ok(Object::Integer(val.abs())),
+        [other] => Err(EvalError::TypeError {
+            operator: "abs".to_string(),
+            got: format!("{:?}", other),
+        }),
+        _ => Err(EvalError::ArityMismatch { expected: 1, got: args.len() }),
+    }
+}
+
+fn builtin_type(args: Vec<Object>) -> Result<Object, EvalError> {
+    match args.as_slice() {
+        [object] => Ok(Object::String(match object {
+            Object::Integer(_) => "Integer",
+            Object::Float(_) => "Float",
+            Object::Boolean(_) => "Boolean",
+            Object::String(_) => "String",
+            Object::Null => "Null",
+            Object::Return(_) => "Return",
+            Object::Function { .. } => "Function",
+            Object::Builtin(_) => "Builtin",
+        }.to_string())),
+        _ => Err(EvalError::ArityMismatch { expected: 1, got: args.len() }),
+    }
 }
 
-fn eval_expr(expression: Expr) -> Object {
+fn eval_expr(expression: Expr, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
     match expression {
-        Expr::Const(num) => Object::Integer(num),
-        Expr::Boolean(val) => Object::Boolean(val),
+        Expr::Const(num) => Ok(Object::Integer(num)),
+        Expr::FloatConst(num) => Ok(Object::Float(num)),
+        Expr::StringConst(val) => Ok(Object::String(val)),
+        Expr::Boolean(val) => Ok(Object::Boolean(val)),
+        Expr::Identifier(name) => {
+            if let Some(builtin) = lookup_builtin(&name) {
+                return Ok(builtin);
+            }
+
+            env.borrow()
+                .get(&name)
+                .ok_or_else(|| EvalError::UndefinedVariable(name))
+        },
         Expr::Prefix { prefix: Prefix::Bang, value: expr } => {
-            match eval_expr(*expr) {
-                Object::Boolean(val) => Object::Boolean(!val),
-                _ => panic!("! operator only valid for boolean type"),
+            match eval_expr(*expr, env)? {
+                Object::Boolean(val) => Ok(Object::Boolean(!val)),
+                other => Err(EvalError::TypeError {
+                    operator: "!".to_string(),
+                    got: format!("{:?}", other),
+                }),
             }
         },
         Expr::Prefix { prefix: Prefix::Minus, value: expr } => {
-            match eval_expr(*expr) {
-                Object::Integer(val) => Object::Integer(-val),
-                _ => panic!("minus operator only valid for integer type"),
+            match eval_expr(*expr, env)? {
+                Object::Integer(val) => Ok(Object::Integer(-val)),
+                Object::Float(val) => Ok(Object::Float(-val)),
+                other => Err(EvalError::TypeError {
+                    operator: "-".to_string(),
+                    got: format!("{:?}", other),
+                }),
             }
         },
         Expr::Infix { left, operator: Operator::Plus, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Integer(left + right),
-                _ => panic!("plus operator only valid on integer types")
+            match (eval_expr(*left, env)?, eval_expr(*right, env)?) {
+                (Object::Integer(left), Object::Integer(right)) => Ok(Object::Integer(left + right)),
+                (left, right) => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Float(left + right)),
+                    _ => Err(EvalError::TypeError {
+                        operator: "+".to_string(),
+                        got: format!("{:?}, {:?}", left, right),
+                    }),
+                },
             }
         },
         Expr::Infix { left, operator: Operator::Minus, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Integer(left - right),
-                _ => panic!("minus operator only valid on integer types")
+            match (eval_expr(*left, env)?, eval_expr(*right, env)?) {
+                (Object::Integer(left), Object::Integer(right)) => Ok(Object::Integer(left - right)),
+                (left, right) => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Float(left - right)),
+                    _ => Err(EvalError::TypeError {
+                        operator: "-".to_string(),
+                        got: format!("{:?}, {:?}", left, right),
+                    }),
+                },
             }
         },
         Expr::Infix { left, operator: Operator::Multiply, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Integer(left * right),
-                _ => panic!("multiply operator only valid on integer types")
+            match (eval_expr(*left, env)?, eval_expr(*right, env)?) {
+                (Object::Integer(left), Object::Integer(right)) => Ok(Object::Integer(left * right)),
+                (left, right) => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Float(left * right)),
+                    _ => Err(EvalError::TypeError {
+                        operator: "*".to_string(),
+                        got: format!("{:?}, {:?}", left, right),
+                    }),
+                },
             }
         },
         Expr::Infix { left, operator: Operator::Divide, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Integer(left / right),
-                _ => panic!("divide operator only valid on integer types")
+            match (eval_expr(*left, env)?, eval_expr(*right, env)?) {
+                (Object::Integer(_), Object::Integer(0)) => Err(EvalError::DivisionByZero),
+                (Object::Integer(left), Object::Integer(right)) => Ok(Object::Integer(left / right)),
+                (left, right) => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Float(left / right)),
+                    _ => Err(EvalError::TypeError {
+                        operator: "/".to_string(),
+                        got: format!("{:?}, {:?}", left, right),
+                    }),
+                },
             }
         },
         Expr::Infix { left, operator: Operator::LessThan, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Boolean(left < right),
-                _ => panic!("less than operator only valid on integer types")
+            let left = eval_expr(*left, env)?;
+            let right = eval_expr(*right, env)?;
+            match (as_f64(&left), as_f64(&right)) {
+                (Some(left), Some(right)) => Ok(Object::Boolean(left < right)),
+                _ => Err(EvalError::TypeError {
+                    operator: "<".to_string(),
+                    got: format!("{:?}, {:?}", left, right),
+                }),
             }
         },
         Expr::Infix { left, operator: Operator::GreaterThan, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Boolean(left > right),
-                _ => panic!("greater than operator only valid on integer types")
+            let left = eval_expr(*left, env)?;
+            let right = eval_expr(*right, env)?;
+            match (as_f64(&left), as_f64(&right)) {
+                (Some(left), Some(right)) => Ok(Object::Boolean(left > right)),
+                _ => Err(EvalError::TypeError {
+                    operator: ">".to_string(),
+                    got: format!("{:?}, {:?}", left, right),
+                }),
             }
         },
         Expr::Infix { left, operator: Operator::Equals, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Boolean(left == right),
-                (Object::Boolean(left), Object::Boolean(right)) => Object::Boolean(left == right),
-                _ => panic!("equals operator used on invalid types")
+            let left = eval_expr(*left, env)?;
+            let right = eval_expr(*right, env)?;
+            match (&left, &right) {
+                (Object::Boolean(left), Object::Boolean(right)) => Ok(Object::Boolean(left == right)),
+                _ => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Boolean(left == right)),
+                    _ => Ok(Object::Boolean(false)),
+                },
             }
         },
         Expr::Infix { left, operator: Operator::NotEquals, right } => {
-            match (eval_expr(*left), eval_expr(*right)) {
-                (Object::Integer(left), Object::Integer(right)) => Object::Boolean(left != right),
-                (Object::Boolean(left), Object::Boolean(right)) => Object::Boolean(left != right),
-                _ => panic!("not equals operator used on invalid types")
+            let left = eval_expr(*left, env)?;
+            let right = eval_expr(*right, env)?;
+            match (&left, &right) {
+                (Object::Boolean(left), Object::Boolean(right)) => Ok(Object::Boolean(left != right)),
+                _ => match (as_f64(&left), as_f64(&right)) {
+                    (Some(left), Some(right)) => Ok(Object::Boolean(left != right)),
+                    _ => Ok(Object::Boolean(true)),
+                },
             }
         },
         Expr::If { condition, consequence, alternative } => {
-            if eval_expr(*condition) == Object::Boolean(true) {
-                eval_statements(consequence)
+            if eval_expr(*condition, env)? == Object::Boolean(true) {
+                eval_block(consequence, env)
             } else {
-                eval_statements(alternative)
+                eval_block(alternative, env)
+            }
+        },
+        Expr::Function { params, body } => {
+            Ok(Object::Function {
+                params,
+                body,
+                env: Rc::clone(env),
+            })
+        },
+        Expr::Call { function, arguments } => {
+            let callee = eval_expr(*function, env)?;
+
+            let mut args = Vec::with_capacity(arguments.len());
+            for argument in arguments {
+                args.push(eval_expr(argument, env)?);
+            }
+
+            match callee {
+                Object::Builtin(builtin) => builtin(args),
+                Object::Function { params, body, env: closure_env } => {
+                    if params.len() != args.len() {
+                        return Err(EvalError::ArityMismatch {
+                            expected: params.len(),
+                            got: args.len(),
+                        });
+                    }
+
+                    let call_env = Environment::child(closure_env);
+                    for (param, arg) in params.into_iter().zip(args) {
+                        call_env.borrow_mut().set(param, arg);
+                    }
+
+                    match eval_statements(body, &call_env)? {
+                        Object::Return(value) => Ok(*value),
+                        result => Ok(result),
+                    }
+                },
+                other => Err(EvalError::TypeError {
+                    operator: "call".to_string(),
+                    got: format!("{:?}", other),
+                }),
             }
         },
         _ => panic!("eval expr not implemented for this type")
     }
 }
 
-fn eval_statement(statement: Statement) -> Object {
+fn eval_statement(statement: Statement, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
     match statement {
-        Statement::Expression(expr) => eval_expr(expr),
-        Statement::Return{value: expr} => Object::Return(Box::new(eval_expr(expr))),
+        Statement::Expression(expr) => eval_expr(expr, env),
+        Statement::Return{value: expr} => Ok(Object::Return(Box::new(eval_expr(expr, env)?))),
+        Statement::Let{name, value} => {
+            let value = eval_expr(value, env)?;
+            env.borrow_mut().set(name, value);
+            Ok(Object::Null)
+        },
         _ => panic!("unsupported statement type"),
     }
 }
 
-fn eval_statements(statements: Vec<Statement>) -> Object {
+fn eval_statements(statements: Vec<Statement>, env: &Rc<RefCell<Environment>>) -> Result<Object, EvalError> {
     let mut result = Object::Null;
 
     for statement in statements {
-        result = eval_statement(statement);
+        result = eval_statement(statement, env)?;
 
         if let &Object::Return(_) = &result {
-            return result;
+            return Ok(result);
         }
     }
 
-    result
+    Ok(result)
 }
 
-pub fn eval_program(statements: Vec<Statement>) -> Object {
-    let result = eval_statements(statements);
+pub fn eval_program(statements: Vec<Statement>) -> Result<Object, EvalError> {
+    let env = Environment::new();
+    let result = eval_statements(statements, &env)?;
 
     // if object is return type, unwrap it
     if let &Object::Return(_) = &result {
         match result {
-            Object::Return(res) => return *res,
+            Object::Return(res) => return Ok(*res),
             _ => unreachable!(),
         }
     }
 
-    result
+    Ok(result)
+}
+
+/// Like `eval_program`, but runs the static analyzer first and refuses to
+/// evaluate the program if it reports any type errors.
+pub fn eval_program_checked(statements: Vec<Statement>) -> Result<Object, EvalError> {
+    if let Err(errors) = typecheck::analyze(&statements) {
+        return Err(EvalError::TypeCheckFailed(errors));
+    }
+
+    eval_program(statements)
 }
 
 #[cfg(test)]
@@ -169,6 +437,40 @@ mod tests {
         test_eval("(1 > 2) == false;", Object::Boolean(true));
     }
 
+    #[test]
+    fn eval_float_literal() {
+        test_eval("5.0;", Object::Float(5.0));
+        test_eval("-5.0;", Object::Float(-5.0));
+    }
+
+    #[test]
+    fn eval_infix_float() {
+        test_eval("5.0 / 4.0;", Object::Float(1.25));
+        test_eval("5.0 + 5.0;", Object::Float(10.0));
+        test_eval("5.0 - 5.0;", Object::Float(0.0));
+        test_eval("5.0 * 5.0;", Object::Float(25.0));
+        test_eval("5.0 > 1.0;", Object::Boolean(true));
+        test_eval("5.0 < 1.0;", Object::Boolean(false));
+        test_eval("5.0 == 5.0;", Object::Boolean(true));
+        test_eval("5.0 != 5.0;", Object::Boolean(false));
+    }
+
+    #[test]
+    fn eval_infix_mixed_numeric() {
+        test_eval("5 / 4.0;", Object::Float(1.25));
+        test_eval("1.0 + 3;", Object::Float(4.0));
+        test_eval("5 + 5.0;", Object::Float(10.0));
+        test_eval("5 > 4.0;", Object::Boolean(true));
+        test_eval("5 == 5.0;", Object::Boolean(true));
+        test_eval("5 != 5.0;", Object::Boolean(false));
+    }
+
+    #[test]
+    fn eval_infix_incompatible_categories() {
+        test_eval("5 == true;", Object::Boolean(false));
+        test_eval("5 != true;", Object::Boolean(true));
+    }
+
     #[test]
     fn eval_infix_nested_types() {
         test_eval("(1 + 2) + 3;", Object::Integer(6));
@@ -206,19 +508,176 @@ mod tests {
         "#, Object::Integer(10));
     }
 
-//    #[test]
-//    fn eval_binding() {
-//        test_eval("let a = 10; a;", Object::Integer(10));
-//    }
+    #[test]
+    fn eval_err_type_mismatch() {
+        test_eval_err("5 + true;", EvalError::TypeError {
+            operator: "+".to_string(),
+            got: "Integer(5), Boolean(true)".to_string(),
+        });
+        test_eval_err("!5;", EvalError::TypeError {
+            operator: "!".to_string(),
+            got: "Integer(5)".to_string(),
+        });
+    }
+
+    #[test]
+    fn eval_err_division_by_zero() {
+        test_eval_err("5 / 0;", EvalError::DivisionByZero);
+    }
+
+    #[test]
+    fn eval_binding() {
+        test_eval("let a = 10; a;", Object::Integer(10));
+    }
+
+    #[test]
+    fn eval_binding_shadowing() {
+        test_eval("let a = 10; let a = a + 5; a;", Object::Integer(15));
+        test_eval("let a = 1; if (true) { let a = 2; a; };", Object::Integer(2));
+        test_eval("let a = 1; if (true) { let a = 2; }; a;", Object::Integer(1));
+    }
+
+    #[test]
+    fn eval_binding_undefined() {
+        test_eval_err("a;", EvalError::UndefinedVariable("a".to_string()));
+        test_eval_err("let a = 10; b;", EvalError::UndefinedVariable("b".to_string()));
+    }
+
+    #[test]
+    fn eval_function_call() {
+        test_eval("let add = fn(a, b) { a + b; }; add(1, 2);", Object::Integer(3));
+        test_eval("let identity = fn(a) { a; }; identity(5);", Object::Integer(5));
+        test_eval("let identity = fn(a) { return a; }; identity(5);", Object::Integer(5));
+    }
+
+    #[test]
+    fn eval_function_closure() {
+        test_eval(r#"
+            let new_adder = fn(a) { fn(b) { a + b; }; };
+            let add_two = new_adder(2);
+            add_two(3);
+        "#, Object::Integer(5));
+    }
+
+    #[test]
+    fn eval_function_recursion() {
+        test_eval(r#"
+            let counter = fn(x) {
+                if (x > 0) {
+                    return counter(x - 1);
+                };
+
+                return x;
+            };
+            counter(5);
+        "#, Object::Integer(0));
+    }
+
+    #[test]
+    fn eval_function_arity_mismatch() {
+        test_eval_err("let add = fn(a, b) { a + b; }; add(1);", EvalError::ArityMismatch {
+            expected: 2,
+            got: 1,
+        });
+    }
+
+    #[test]
+    fn eval_builtin_len() {
+        test_eval(r#"len("hello");"#, Object::Integer(5));
+        test_eval_err("len(5);", EvalError::TypeError {
+            operator: "len".to_string(),
+            got: "Integer(5)".to_string(),
+        });
+    }
+
+    #[test]
+    fn eval_builtin_min_max() {
+        test_eval("min(3, 1, 2);", Object::Integer(1));
+        test_eval("max(3, 1, 2);", Object::Integer(3));
+    }
+
+    #[test]
+    fn eval_builtin_abs() {
+        test_eval("abs(-5);", Object::Integer(5));
+        test_eval("abs(5);", Object::Integer(5));
+    }
+
+    #[test]
+    fn eval_builtin_type() {
+        test_eval("type(5);", Object::String("Integer".to_string()));
+        test_eval("type(5.0);", Object::String("Float".to_string()));
+        test_eval("type(true);", Object::String("Boolean".to_string()));
+        test_eval(r#"type("hello");"#, Object::String("String".to_string()));
+    }
+
+    #[test]
+    fn eval_builtin_arity_mismatch() {
+        test_eval_err("abs(1, 2);", EvalError::ArityMismatch { expected: 1, got: 2 });
+    }
+
+    #[test]
+    fn eval_program_checked_refuses_type_errors() {
+        let mut tokens = lexer().parse("5 + true;".as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        match eval_program_checked(ast) {
+            Err(EvalError::TypeCheckFailed(errors)) => assert_eq!(1, errors.len()),
+            other => panic!("expected TypeCheckFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn eval_program_checked_runs_valid_programs() {
+        let mut tokens = lexer().parse("let a = 5; a + 1;".as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(Object::Integer(6)), eval_program_checked(ast));
+    }
+
+    #[test]
+    fn eval_program_checked_accepts_builtin_calls() {
+        let mut tokens = lexer().parse(r#"len("hi");"#.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(Object::Integer(2)), eval_program_checked(ast));
+    }
+
+    #[test]
+    fn eval_program_checked_accepts_recursive_let() {
+        let mut tokens = lexer().parse(r#"
+            let counter = fn(x) {
+                if (x > 0) {
+                    return counter(x - 1);
+                };
+
+                return x;
+            };
+            counter(5);
+        "#.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(Object::Integer(0)), eval_program_checked(ast));
+    }
 
     fn test_eval(input: &str, expected: Object) {
         let mut tokens = lexer().parse(input.as_bytes()).unwrap();
         let ast = parse(&mut tokens);
-        let obj = eval_program(ast);
+        let obj = eval_program(ast).unwrap();
 
         assert_eq!(
             expected,
             obj
         );
     }
+
+    fn test_eval_err(input: &str, expected: EvalError) {
+        let mut tokens = lexer().parse(input.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+        let err = eval_program(ast).unwrap_err();
+
+        assert_eq!(
+            expected,
+            err
+        );
+    }
 }
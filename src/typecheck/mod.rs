@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+
+use crate::parser::Expr;
+use crate::parser::Operator;
+use crate::parser::Prefix;
+use crate::parser::Statement;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Type {
+    Int,
+    Bool,
+    Unknown,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TypeError {
+    PrefixMismatch { prefix: String, got: Type, expr: String },
+    InfixMismatch { operator: String, left: Type, right: Type, expr: String },
+    UndefinedVariable(String),
+}
+
+struct Scope {
+    bindings: Vec<HashMap<String, Type>>,
+    errors: Vec<TypeError>,
+}
+
+impl Scope {
+    fn new() -> Self {
+        Scope { bindings: vec![HashMap::new()], errors: Vec::new() }
+    }
+
+    fn push(&mut self) {
+        self.bindings.push(HashMap::new());
+    }
+
+    fn pop(&mut self) {
+        self.bindings.pop();
+    }
+
+    fn bind(&mut self, name: String, ty: Type) {
+        self.bindings.last_mut().expect("at least one scope").insert(name, ty);
+    }
+
+    fn lookup(&self, name: &str) -> Option<Type> {
+        self.bindings.iter().rev().find_map(|scope| scope.get(name).copied())
+    }
+}
+
+pub fn analyze(statements: &[Statement]) -> Result<(), Vec<TypeError>> {
+    let mut scope = Scope::new();
+
+    for statement in statements {
+        infer_statement(statement, &mut scope);
+    }
+
+    if scope.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(scope.errors)
+    }
+}
+
+fn infer_statement(statement: &Statement, scope: &mut Scope) {
+    match statement {
+        Statement::Expression(expr) => {
+            infer_expr(expr, scope);
+        },
+        Statement::Return { value } => {
+            infer_expr(value, scope);
+        },
+        Statement::Let { name, value } => {
+            // bind before inferring the RHS so a self-referential (recursive) let
+            // doesn't see its own name as undefined
+            scope.bind(name.clone(), Type::Unknown);
+            let ty = infer_expr(value, scope);
+            scope.bind(name.clone(), ty);
+        },
+        _ => {},
+    }
+}
+
+fn infer_expr(expr: &Expr, scope: &mut Scope) -> Type {
+    match expr {
+        Expr::Const(_) => Type::Int,
+        Expr::FloatConst(_) => Type::Unknown,
+        Expr::StringConst(_) => Type::Unknown,
+        Expr::Boolean(_) => Type::Bool,
+        Expr::Identifier(name) => {
+            if is_builtin(name) {
+                return Type::Unknown;
+            }
+
+            match scope.lookup(name) {
+                Some(ty) => ty,
+                None => {
+                    scope.errors.push(TypeError::UndefinedVariable(name.clone()));
+                    Type::Unknown
+                },
+            }
+        },
+        Expr::Prefix { prefix: Prefix::Bang, value } => {
+            match infer_expr(value, scope) {
+                Type::Bool | Type::Unknown => Type::Bool,
+                got => {
+                    scope.errors.push(TypeError::PrefixMismatch {
+                        prefix: "!".to_string(),
+                        got,
+                        expr: format!("{:?}", expr),
+                    });
+                    Type::Unknown
+                },
+            }
+        },
+        Expr::Prefix { prefix: Prefix::Minus, value } => {
+            match infer_expr(value, scope) {
+                Type::Int | Type::Unknown => Type::Int,
+                got => {
+                    scope.errors.push(TypeError::PrefixMismatch {
+                        prefix: "-".to_string(),
+                        got,
+                        expr: format!("{:?}", expr),
+                    });
+                    Type::Unknown
+                },
+            }
+        },
+        Expr::Infix { left, operator: operator @ (Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide), right } => {
+            let left_ty = infer_expr(left, scope);
+            let right_ty = infer_expr(right, scope);
+
+            match (left_ty, right_ty) {
+                (Type::Unknown, _) | (_, Type::Unknown) => Type::Unknown,
+                (Type::Int, Type::Int) => Type::Int,
+                (left, right) => {
+                    scope.errors.push(TypeError::InfixMismatch {
+                        operator: operator_symbol(operator).to_string(),
+                        left,
+                        right,
+                        expr: format!("{:?}", expr),
+                    });
+                    Type::Unknown
+                },
+            }
+        },
+        Expr::Infix { left, operator: operator @ (Operator::LessThan | Operator::GreaterThan), right } => {
+            let left_ty = infer_expr(left, scope);
+            let right_ty = infer_expr(right, scope);
+
+            match (left_ty, right_ty) {
+                (Type::Unknown, _) | (_, Type::Unknown) => Type::Bool,
+                (Type::Int, Type::Int) => Type::Bool,
+                (left, right) => {
+                    scope.errors.push(TypeError::InfixMismatch {
+                        operator: operator_symbol(operator).to_string(),
+                        left,
+                        right,
+                        expr: format!("{:?}", expr),
+                    });
+                    Type::Unknown
+                },
+            }
+        },
+        Expr::Infix { left, operator: Operator::Equals | Operator::NotEquals, right } => {
+            infer_expr(left, scope);
+            infer_expr(right, scope);
+            Type::Bool
+        },
+        Expr::If { condition, consequence, alternative } => {
+            infer_expr(condition, scope);
+
+            scope.push();
+            for statement in consequence {
+                infer_statement(statement, scope);
+            }
+            scope.pop();
+
+            scope.push();
+            for statement in alternative {
+                infer_statement(statement, scope);
+            }
+            scope.pop();
+
+            Type::Unknown
+        },
+        Expr::Function { params, body } => {
+            scope.push();
+            for param in params {
+                scope.bind(param.clone(), Type::Unknown);
+            }
+            for statement in body {
+                infer_statement(statement, scope);
+            }
+            scope.pop();
+
+            Type::Unknown
+        },
+        Expr::Call { function, arguments } => {
+            infer_expr(function, scope);
+            for argument in arguments {
+                infer_expr(argument, scope);
+            }
+
+            Type::Unknown
+        },
+        _ => Type::Unknown,
+    }
+}
+
+// Mirrors the names resolved by `eval::lookup_builtin`, which is checked
+// ahead of the environment during identifier lookup at eval time.
+fn is_builtin(name: &str) -> bool {
+    matches!(name, "len" | "min" | "max" | "abs" | "type")
+}
+
+fn operator_symbol(operator: &Operator) -> &'static str {
+    match operator {
+        Operator::Plus => "+",
+        Operator::Minus => "-",
+        Operator::Multiply => "*",
+        Operator::Divide => "/",
+        Operator::LessThan => "<",
+        Operator::GreaterThan => ">",
+        Operator::Equals => "==",
+        Operator::NotEquals => "!=",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::lexer;
+    use crate::parser::parse;
+
+    #[test]
+    fn analyze_arithmetic_on_boolean() {
+        test_analyze_err("5 + true;", TypeError::InfixMismatch {
+            operator: "+".to_string(),
+            left: Type::Int,
+            right: Type::Bool,
+            expr: "Infix { left: Const(5), operator: Plus, right: Boolean(true) }".to_string(),
+        });
+    }
+
+    #[test]
+    fn analyze_bang_on_integer() {
+        test_analyze_err("!5;", TypeError::PrefixMismatch {
+            prefix: "!".to_string(),
+            got: Type::Int,
+            expr: "Prefix { prefix: Bang, value: Const(5) }".to_string(),
+        });
+    }
+
+    #[test]
+    fn analyze_comparison_incompatible_categories() {
+        test_analyze_err("true < 5;", TypeError::InfixMismatch {
+            operator: "<".to_string(),
+            left: Type::Bool,
+            right: Type::Int,
+            expr: "Infix { left: Boolean(true), operator: LessThan, right: Const(5) }".to_string(),
+        });
+    }
+
+    #[test]
+    fn analyze_undefined_identifier() {
+        test_analyze_err("a;", TypeError::UndefinedVariable("a".to_string()));
+    }
+
+    #[test]
+    fn analyze_collects_multiple_errors() {
+        let mut tokens = lexer().parse("5 + true; !5;".as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+        let errors = analyze(&ast).unwrap_err();
+
+        assert_eq!(2, errors.len());
+    }
+
+    #[test]
+    fn analyze_valid_program() {
+        let mut tokens = lexer().parse("let a = 5; a + 1;".as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(()), analyze(&ast));
+    }
+
+    #[test]
+    fn analyze_recursive_let() {
+        let mut tokens = lexer().parse(r#"
+            let counter = fn(x) {
+                if (x > 0) {
+                    return counter(x - 1);
+                };
+
+                return x;
+            };
+        "#.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(()), analyze(&ast));
+    }
+
+    #[test]
+    fn analyze_builtin_call() {
+        let mut tokens = lexer().parse(r#"len("hi");"#.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        assert_eq!(Ok(()), analyze(&ast));
+    }
+
+    fn test_analyze_err(input: &str, expected: TypeError) {
+        let mut tokens = lexer().parse(input.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+        let errors = analyze(&ast).unwrap_err();
+
+        assert!(errors.contains(&expected), "{:?} did not contain {:?}", errors, expected);
+    }
+}
@@ -0,0 +1,443 @@
+use crate::eval::EvalError;
+use crate::eval::Object;
+use crate::parser::Expr;
+use crate::parser::Operator;
+use crate::parser::Prefix;
+use crate::parser::Statement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpCode {
+    Const(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Bang,
+    LessThan,
+    GreaterThan,
+    Equal,
+    NotEqual,
+    True,
+    False,
+    Null,
+    JumpIfFalse(usize),
+    Jump(usize),
+    Return,
+}
+
+#[derive(Debug, Default)]
+pub struct Bytecode {
+    pub instructions: Vec<OpCode>,
+    pub constants: Vec<Object>,
+}
+
+impl Bytecode {
+    fn add_constant(&mut self, object: Object) -> usize {
+        self.constants.push(object);
+        self.constants.len() - 1
+    }
+}
+
+// The VM backend only targets the integer-arithmetic/boolean/if/return subset
+// of Monkey; float and string literals, `let`, identifiers, functions and
+// calls are rejected with `EvalError::Unsupported` rather than compiled, since
+// the opcode set has no way to represent bindings or closures.
+pub fn compile(statements: Vec<Statement>) -> Result<Bytecode, EvalError> {
+    let mut bytecode = Bytecode::default();
+    compile_statements(statements, &mut bytecode)?;
+    Ok(bytecode)
+}
+
+fn compile_statements(statements: Vec<Statement>, bytecode: &mut Bytecode) -> Result<(), EvalError> {
+    for statement in statements {
+        compile_statement(statement, bytecode)?;
+    }
+    Ok(())
+}
+
+fn compile_statement(statement: Statement, bytecode: &mut Bytecode) -> Result<(), EvalError> {
+    match statement {
+        Statement::Expression(expr) => compile_expr(expr, bytecode),
+        Statement::Return{value: expr} => {
+            compile_expr(expr, bytecode)?;
+            bytecode.instructions.push(OpCode::Return);
+            Ok(())
+        },
+        other => Err(EvalError::Unsupported(format!(
+            "the VM backend does not support this statement yet: {:?}",
+            other
+        ))),
+    }
+}
+
+fn compile_expr(expression: Expr, bytecode: &mut Bytecode) -> Result<(), EvalError> {
+    match expression {
+        Expr::Const(num) => {
+            let index = bytecode.add_constant(Object::Integer(num));
+            bytecode.instructions.push(OpCode::Const(index));
+            Ok(())
+        },
+        Expr::Boolean(true) => {
+            bytecode.instructions.push(OpCode::True);
+            Ok(())
+        },
+        Expr::Boolean(false) => {
+            bytecode.instructions.push(OpCode::False);
+            Ok(())
+        },
+        Expr::Prefix { prefix: Prefix::Bang, value } => {
+            compile_expr(*value, bytecode)?;
+            bytecode.instructions.push(OpCode::Bang);
+            Ok(())
+        },
+        Expr::Prefix { prefix: Prefix::Minus, value } => {
+            compile_expr(*value, bytecode)?;
+            bytecode.instructions.push(OpCode::Neg);
+            Ok(())
+        },
+        Expr::Infix { left, operator, right } => {
+            compile_expr(*left, bytecode)?;
+            compile_expr(*right, bytecode)?;
+            bytecode.instructions.push(match operator {
+                Operator::Plus => OpCode::Add,
+                Operator::Minus => OpCode::Sub,
+                Operator::Multiply => OpCode::Mul,
+                Operator::Divide => OpCode::Div,
+                Operator::LessThan => OpCode::LessThan,
+                Operator::GreaterThan => OpCode::GreaterThan,
+                Operator::Equals => OpCode::Equal,
+                Operator::NotEquals => OpCode::NotEqual,
+            });
+            Ok(())
+        },
+        Expr::If { condition, consequence, alternative } => {
+            compile_expr(*condition, bytecode)?;
+
+            let jump_if_false = bytecode.instructions.len();
+            bytecode.instructions.push(OpCode::JumpIfFalse(0));
+
+            compile_statements(consequence, bytecode)?;
+
+            let jump = bytecode.instructions.len();
+            bytecode.instructions.push(OpCode::Jump(0));
+
+            let alternative_start = bytecode.instructions.len();
+            bytecode.instructions[jump_if_false] = OpCode::JumpIfFalse(alternative_start);
+
+            compile_statements(alternative, bytecode)?;
+
+            let after = bytecode.instructions.len();
+            bytecode.instructions[jump] = OpCode::Jump(after);
+
+            Ok(())
+        },
+        other => Err(EvalError::Unsupported(format!(
+            "the VM backend does not support this expression yet: {:?}",
+            other
+        ))),
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Vm {
+    stack: Vec<Object>,
+    sp: usize,
+}
+
+impl Vm {
+    pub fn new() -> Self {
+        Vm { stack: Vec::new(), sp: 0 }
+    }
+
+    fn push(&mut self, object: Object) {
+        if self.sp == self.stack.len() {
+            self.stack.push(object);
+        } else {
+            self.stack[self.sp] = object;
+        }
+        self.sp += 1;
+    }
+
+    fn pop(&mut self) -> Object {
+        self.sp -= 1;
+        self.stack[self.sp].clone()
+    }
+
+    fn top(&self) -> Option<&Object> {
+        if self.sp == 0 {
+            None
+        } else {
+            Some(&self.stack[self.sp - 1])
+        }
+    }
+
+    pub fn run(&mut self, bytecode: &Bytecode) -> Result<Object, EvalError> {
+        let mut ip = 0;
+
+        while ip < bytecode.instructions.len() {
+            match &bytecode.instructions[ip] {
+                OpCode::Const(index) => self.push(bytecode.constants[*index].clone()),
+                OpCode::True => self.push(Object::Boolean(true)),
+                OpCode::False => self.push(Object::Boolean(false)),
+                OpCode::Null => self.push(Object::Null),
+                OpCode::Add => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Integer(left + right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "+".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::Sub => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Integer(left - right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "-".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::Mul => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Integer(left * right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "*".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::Div => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(_), Object::Integer(0)) => return Err(EvalError::DivisionByZero),
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Integer(left / right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "/".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::LessThan => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Boolean(left < right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "<".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::GreaterThan => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Boolean(left > right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: ">".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::Equal => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Boolean(left == right)),
+                        (Object::Boolean(left), Object::Boolean(right)) => self.push(Object::Boolean(left == right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "==".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::NotEqual => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    match (left, right) {
+                        (Object::Integer(left), Object::Integer(right)) => self.push(Object::Boolean(left != right)),
+                        (Object::Boolean(left), Object::Boolean(right)) => self.push(Object::Boolean(left != right)),
+                        (left, right) => return Err(EvalError::TypeError {
+                            operator: "!=".to_string(),
+                            got: format!("{:?}, {:?}", left, right),
+                        }),
+                    }
+                },
+                OpCode::Neg => {
+                    match self.pop() {
+                        Object::Integer(val) => self.push(Object::Integer(-val)),
+                        other => return Err(EvalError::TypeError {
+                            operator: "-".to_string(),
+                            got: format!("{:?}", other),
+                        }),
+                    }
+                },
+                OpCode::Bang => {
+                    match self.pop() {
+                        Object::Boolean(val) => self.push(Object::Boolean(!val)),
+                        other => return Err(EvalError::TypeError {
+                            operator: "!".to_string(),
+                            got: format!("{:?}", other),
+                        }),
+                    }
+                },
+                OpCode::JumpIfFalse(target) => {
+                    let target = *target;
+                    if self.pop() == Object::Boolean(false) {
+                        ip = target;
+                        continue;
+                    }
+                },
+                OpCode::Jump(target) => {
+                    ip = *target;
+                    continue;
+                },
+                OpCode::Return => return Ok(self.top().cloned().unwrap_or(Object::Null)),
+            }
+
+            ip += 1;
+        }
+
+        Ok(self.top().cloned().unwrap_or(Object::Null))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::lexer;
+    use crate::parser::parse;
+
+    #[test]
+    fn vm_int_literal() {
+        test_vm("5;", Object::Integer(5));
+    }
+
+    #[test]
+    fn vm_bool() {
+        test_vm("true;", Object::Boolean(true));
+        test_vm("false;", Object::Boolean(false));
+    }
+
+    #[test]
+    fn vm_bang() {
+        test_vm("!true;", Object::Boolean(false));
+        test_vm("!false;", Object::Boolean(true));
+        test_vm("!(1 > 2);", Object::Boolean(true));
+    }
+
+    #[test]
+    fn vm_negative() {
+        test_vm("-5;", Object::Integer(-5));
+        test_vm("-(1 - 2);", Object::Integer(1));
+    }
+
+    #[test]
+    fn vm_infix() {
+        test_vm("5 + 5;", Object::Integer(10));
+        test_vm("5 - 5;", Object::Integer(0));
+        test_vm("5 * 5;", Object::Integer(25));
+        test_vm("5 / 5;", Object::Integer(1));
+        test_vm("5 > 1;", Object::Boolean(true));
+        test_vm("5 < 1;", Object::Boolean(false));
+        test_vm("5 == 1;", Object::Boolean(false));
+        test_vm("5 != 1;", Object::Boolean(true));
+        test_vm("true == true;", Object::Boolean(true));
+        test_vm("true != true;", Object::Boolean(false));
+        test_vm("(1 > 2) == false;", Object::Boolean(true));
+    }
+
+    #[test]
+    fn vm_if() {
+        test_vm("if (true) { 10; };", Object::Integer(10));
+        test_vm("if (false) { 10; };", Object::Null);
+        test_vm("if (false) { 10; } else { 11; };", Object::Integer(11));
+        test_vm("if (1 > 2) { 10; } else { 11; };", Object::Integer(11));
+        test_vm("if (1 < 2) { 10; } else { 11; };", Object::Integer(10));
+    }
+
+    #[test]
+    fn vm_return() {
+        test_vm("return 10;", Object::Integer(10));
+        test_vm("return 10; 11;", Object::Integer(10));
+        test_vm("9; return 2 * 5; 9;", Object::Integer(10));
+        test_vm(r#"
+            if (10 > 1) {
+              if (10 > 1) {
+                return 10;
+              };
+
+              return 1;
+            };
+        "#, Object::Integer(10));
+    }
+
+    #[test]
+    fn vm_err_type_mismatch() {
+        test_vm_err("5 + true;", EvalError::TypeError {
+            operator: "+".to_string(),
+            got: "Integer(5), Boolean(true)".to_string(),
+        });
+        test_vm_err("!5;", EvalError::TypeError {
+            operator: "!".to_string(),
+            got: "Integer(5)".to_string(),
+        });
+    }
+
+    #[test]
+    fn vm_err_division_by_zero() {
+        test_vm_err("5 / 0;", EvalError::DivisionByZero);
+    }
+
+    #[test]
+    fn vm_compile_rejects_unsupported_constructs() {
+        // let bindings, identifiers and function calls aren't representable
+        // in the current opcode set yet; compile() must report that instead
+        // of panicking the process.
+        test_compile_err("let x = 5; x;");
+        test_compile_err("let add = fn(a, b) { a + b; }; add(1, 2);");
+    }
+
+    fn test_vm(input: &str, expected: Object) {
+        let mut tokens = lexer().parse(input.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+        let bytecode = compile(ast).unwrap();
+        let obj = Vm::new().run(&bytecode).unwrap();
+
+        assert_eq!(
+            expected,
+            obj
+        );
+    }
+
+    fn test_vm_err(input: &str, expected: EvalError) {
+        let mut tokens = lexer().parse(input.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+        let bytecode = compile(ast).unwrap();
+        let err = Vm::new().run(&bytecode).unwrap_err();
+
+        assert_eq!(
+            expected,
+            err
+        );
+    }
+
+    fn test_compile_err(input: &str) {
+        let mut tokens = lexer().parse(input.as_bytes()).unwrap();
+        let ast = parse(&mut tokens);
+
+        match compile(ast) {
+            Err(EvalError::Unsupported(_)) => {},
+            other => panic!("expected Unsupported, got {:?}", other),
+        }
+    }
+}